@@ -3,7 +3,7 @@
 
 use ide_db::base_db::FileId;
 use ide_db::source_change::SourceFileEdit;
-use syntax::{ast, match_ast, AstNode, SyntaxNode};
+use syntax::{ast, match_ast, AstNode, SyntaxNode, TextRange};
 use text_edit::TextEdit;
 
 use crate::{Diagnostic, Fix};
@@ -27,6 +27,7 @@ fn check_expr_field_shorthand(
         Some(it) => it,
         None => return,
     };
+    let mut all_shorthand = AllShorthand::default();
     for record_field in record_field_list.fields() {
         let (name_ref, expr) = match record_field.name_ref().zip(record_field.expr()) {
             Some(it) => it,
@@ -40,12 +41,10 @@ fn check_expr_field_shorthand(
             continue;
         }
 
-        let mut edit_builder = TextEdit::builder();
-        edit_builder.delete(record_field.syntax().text_range());
-        edit_builder.insert(record_field.syntax().text_range().start(), field_name);
-        let edit = edit_builder.finish();
-
         let field_range = record_field.syntax().text_range();
+        let edit = build_field_shorthand_edit(field_range, field_name.clone());
+        all_shorthand.push(field_range, field_name.clone());
+
         acc.push(
             Diagnostic::hint(field_range, "Shorthand struct initialization".to_string()).with_fix(
                 Some(Fix::new(
@@ -56,6 +55,15 @@ fn check_expr_field_shorthand(
             ),
         );
     }
+
+    if let Some((range, edit)) = all_shorthand.finish() {
+        let message = "Multiple fields can use struct shorthand initialization".to_string();
+        acc.push(Diagnostic::hint(range, message).with_fix(Some(Fix::new(
+            "Use shorthand for all fields",
+            SourceFileEdit { file_id, edit }.into(),
+            range,
+        ))));
+    }
 }
 
 fn check_pat_field_shorthand(
@@ -67,6 +75,7 @@ fn check_pat_field_shorthand(
         Some(it) => it,
         None => return,
     };
+    let mut all_shorthand = AllShorthand::default();
     for record_pat_field in record_pat_field_list.fields() {
         let (name_ref, pat) = match record_pat_field.name_ref().zip(record_pat_field.pat()) {
             Some(it) => it,
@@ -80,12 +89,10 @@ fn check_pat_field_shorthand(
             continue;
         }
 
-        let mut edit_builder = TextEdit::builder();
-        edit_builder.delete(record_pat_field.syntax().text_range());
-        edit_builder.insert(record_pat_field.syntax().text_range().start(), field_name);
-        let edit = edit_builder.finish();
-
         let field_range = record_pat_field.syntax().text_range();
+        let edit = build_field_shorthand_edit(field_range, field_name.clone());
+        all_shorthand.push(field_range, field_name.clone());
+
         acc.push(Diagnostic::hint(field_range, "Shorthand struct pattern".to_string()).with_fix(
             Some(Fix::new(
                 "Use struct field shorthand",
@@ -94,6 +101,57 @@ fn check_pat_field_shorthand(
             )),
         ));
     }
+
+    if let Some((range, edit)) = all_shorthand.finish() {
+        let message = "Multiple fields can use struct field shorthand".to_string();
+        acc.push(Diagnostic::hint(range, message).with_fix(Some(Fix::new(
+            "Use shorthand for all fields",
+            SourceFileEdit { file_id, edit }.into(),
+            range,
+        ))));
+    }
+}
+
+fn build_field_shorthand_edit(field_range: TextRange, field_name: String) -> TextEdit {
+    let mut edit_builder = TextEdit::builder();
+    edit_builder.delete(field_range);
+    edit_builder.insert(field_range.start(), field_name);
+    edit_builder.finish()
+}
+
+/// Accumulates the per-field shorthand edits of a single `RecordExpr`/`RecordPat` so that,
+/// once all of its fields have been visited, they can be offered as one combined "fix all" edit.
+///
+/// Fields are visited in source order, so the collected (range, name) pairs are already
+/// non-overlapping and sorted by offset, which is all `TextEdit::builder` requires.
+#[derive(Default)]
+struct AllShorthand {
+    fields: Vec<(TextRange, String)>,
+    range: Option<TextRange>,
+}
+
+impl AllShorthand {
+    fn push(&mut self, field_range: TextRange, field_name: String) {
+        self.range = Some(match self.range {
+            Some(range) => range.cover(field_range),
+            None => field_range,
+        });
+        self.fields.push((field_range, field_name));
+    }
+
+    /// Returns the combined edit and the range it covers, provided more than one field
+    /// was collapsible (a single shorthand opportunity is already covered by its own fix).
+    fn finish(self) -> Option<(TextRange, TextEdit)> {
+        if self.fields.len() < 2 {
+            return None;
+        }
+        let mut edit_builder = TextEdit::builder();
+        for (field_range, field_name) in self.fields {
+            edit_builder.delete(field_range);
+            edit_builder.insert(field_range.start(), field_name);
+        }
+        self.range.map(|range| (range, edit_builder.finish()))
+    }
 }
 
 #[cfg(test)]
@@ -152,6 +210,30 @@ fn main() {
         );
     }
 
+    #[test]
+    fn test_check_expr_field_shorthand_fix_all() {
+        check_fix(
+            r#"
+struct A { a: &'static str, b: &'static str, c: &'static str }
+fn main() {
+    let a = "haha";
+    let b = "bb";
+    let c = "cc";
+    A { a: a<|>, b: b, c: c }
+}
+"#,
+            r#"
+struct A { a: &'static str, b: &'static str, c: &'static str }
+fn main() {
+    let a = "haha";
+    let b = "bb";
+    let c = "cc";
+    A { a, b, c }
+}
+"#,
+        );
+    }
+
     #[test]
     fn test_check_pat_field_shorthand() {
         check_no_diagnostics(
@@ -194,6 +276,24 @@ struct A { a: &'static str, b: &'static str }
 fn f(a: A) {
     let A { a, b } = a;
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_pat_field_shorthand_fix_all() {
+        check_fix(
+            r#"
+struct A { a: &'static str, b: &'static str, c: &'static str }
+fn f(a: A) {
+    let A { a: a<|>, b: b, c: c } = a;
+}
+"#,
+            r#"
+struct A { a: &'static str, b: &'static str, c: &'static str }
+fn f(a: A) {
+    let A { a, b, c } = a;
+}
 "#,
         );
     }
@@ -0,0 +1,205 @@
+//! Diagnoses `RecordExpr`s that omit fields required by the struct/variant they
+//! construct, with no `..rest` functional update to cover the gap.
+
+use hir::Semantics;
+use ide_db::base_db::FileId;
+use ide_db::helpers::FamousDefs;
+use ide_db::source_change::SourceFileEdit;
+use ide_db::RootDatabase;
+use itertools::Itertools;
+use syntax::{ast, ast::edit::IndentLevel, match_ast, AstNode, SyntaxNode, TextRange, T};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) {
+    match_ast! {
+        match node {
+            ast::RecordExpr(it) => check_record_expr(acc, sema, file_id, it),
+            _ => ()
+        }
+    };
+}
+
+fn check_record_expr(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    record_expr: ast::RecordExpr,
+) {
+    let record_field_list = match record_expr.record_expr_field_list() {
+        Some(it) => it,
+        None => return,
+    };
+    // `..rest` already supplies whatever fields the literal itself omits.
+    if record_field_list.spread().is_some() {
+        return;
+    }
+
+    let fields = match sema.record_literal_missing_fields(&record_expr) {
+        missing if !missing.is_empty() => missing,
+        _ => return,
+    };
+
+    let db = sema.db;
+    let krate = match sema.scope(record_expr.syntax()).module() {
+        Some(module) => module.krate(),
+        None => return,
+    };
+    let default_trait = FamousDefs(sema, krate).core_default_Default();
+
+    let field_texts: Vec<_> = fields
+        .iter()
+        .map(|(field, ty)| {
+            let implements_default = default_trait
+                .map(|trait_| ty.impls_trait(db, trait_, &[]))
+                .unwrap_or(false);
+            let placeholder = if implements_default { "Default::default()" } else { "todo!()" };
+            format!("{}: {}", field.name(db), placeholder)
+        })
+        .collect();
+
+    let r_curly = match record_field_list.r_curly_token() {
+        Some(it) => it,
+        None => return,
+    };
+    // The last significant token before `}` — either the preceding field's last
+    // token, a trailing comma, or the opening `{` itself for an empty literal.
+    let last_token = match std::iter::successors(r_curly.prev_token(), |it| it.prev_token())
+        .find(|it| !it.kind().is_trivia())
+    {
+        Some(it) => it,
+        None => return,
+    };
+    let is_empty_literal = last_token.kind() == T!['{'];
+    let has_trailing_comma = last_token.kind() == T![,];
+    let needs_leading_comma = !(is_empty_literal || has_trailing_comma);
+
+    // A literal whose closing brace sits on its own line gets each new field inserted
+    // as its own indented line, leaving the existing trailing newline/indent before
+    // `}` untouched; a single-line literal collapses them onto the same line instead.
+    let is_multiline = std::iter::successors(last_token.next_token(), |it| it.next_token())
+        .take_while(|it| *it != r_curly)
+        .any(|it| it.text().contains('\n'));
+
+    let (edit_range, insert_text) = if is_multiline {
+        let indent = IndentLevel::from_node(record_field_list.syntax()) + 1;
+        let mut insert_text = String::new();
+        for (i, field_text) in field_texts.iter().enumerate() {
+            if i == 0 && needs_leading_comma {
+                insert_text.push(',');
+            }
+            insert_text.push('\n');
+            insert_text.push_str(&indent.to_string());
+            insert_text.push_str(field_text);
+            insert_text.push(',');
+        }
+        (TextRange::empty(last_token.text_range().end()), insert_text)
+    } else {
+        let prefix = if needs_leading_comma { ", " } else { " " };
+        let insert_text = format!("{}{} ", prefix, field_texts.join(", "));
+        let whitespace_range =
+            TextRange::new(last_token.text_range().end(), r_curly.text_range().start());
+        (whitespace_range, insert_text)
+    };
+
+    let mut edit_builder = TextEdit::builder();
+    edit_builder.replace(edit_range, insert_text);
+    let edit = edit_builder.finish();
+
+    let range = record_expr.syntax().text_range();
+    let message = format!(
+        "Missing structure fields:\n{}",
+        fields.iter().map(|(field, _)| format!("- {}", field.name(db))).join("\n")
+    );
+    acc.push(Diagnostic::hint(range, message).with_fix(Some(Fix::new(
+        "Fill missing fields",
+        SourceFileEdit { file_id, edit }.into(),
+        range,
+    ))));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_check_missing_fields() {
+        check_no_diagnostics(
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() { A { a: "hello", b: "world" }; }
+"#,
+        );
+        check_no_diagnostics(
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn f(a: A) { A { a: "hello", ..a }; }
+"#,
+        );
+
+        check_fix(
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() { A<|> { a: "hello" }; }
+"#,
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() { A { a: "hello", b: todo!() }; }
+"#,
+        );
+
+        check_fix(
+            r#"
+//- /main.rs crate:main deps:core
+struct A { a: &'static str, b: u32 }
+fn main() { A<|> {}; }
+//- /core.rs crate:core
+pub mod default {
+    pub trait Default { fn default() -> Self; }
+    impl Default for u32 { fn default() -> Self { 0 } }
+}
+"#,
+            r#"
+struct A { a: &'static str, b: u32 }
+fn main() { A { a: todo!(), b: Default::default() }; }
+"#,
+        );
+
+        check_fix(
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() { A<|> { a: "hello", }; }
+"#,
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() { A { a: "hello", b: todo!() }; }
+"#,
+        );
+
+        check_fix(
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() {
+    A<|> {
+        a: "hello",
+    };
+}
+"#,
+            r#"
+struct A { a: &'static str, b: &'static str }
+fn main() {
+    A {
+        a: "hello",
+        b: todo!(),
+    };
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Suggests removing unnecessary braces in `use` items.
+
+use ide_db::base_db::FileId;
+use ide_db::source_change::SourceFileEdit;
+use itertools::Itertools;
+use syntax::{ast, match_ast, AstNode, Direction, SyntaxNode, TextRange, T};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(acc: &mut Vec<Diagnostic>, file_id: FileId, node: &SyntaxNode) {
+    match_ast! {
+        match node {
+            ast::UseTreeList(it) => check_use_tree_list(acc, file_id, it),
+            _ => ()
+        }
+    };
+}
+
+fn check_use_tree_list(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    use_tree_list: ast::UseTreeList,
+) {
+    let (single_use_tree,) = match use_tree_list.use_trees().collect_tuple() {
+        Some(it) => it,
+        None => return,
+    };
+
+    // `use a::b::{self}` drops the whole `::{self}` tail rather than just the braces,
+    // since `self` on its own is only meaningful inside a brace list.
+    let is_self = single_use_tree.syntax().text() == "self";
+    let (range, replacement) = if is_self {
+        let coloncolon = use_tree_list
+            .syntax()
+            .siblings_with_tokens(Direction::Prev)
+            .find(|it| it.kind() == T![::]);
+        let start = coloncolon.map_or_else(
+            || use_tree_list.syntax().text_range().start(),
+            |it| it.text_range().start(),
+        );
+        (TextRange::new(start, use_tree_list.syntax().text_range().end()), String::new())
+    } else {
+        let range = use_tree_list.syntax().text_range();
+        (range, single_use_tree.syntax().text().to_string())
+    };
+
+    let mut edit_builder = TextEdit::builder();
+    edit_builder.delete(range);
+    edit_builder.insert(range.start(), replacement);
+    let edit = edit_builder.finish();
+
+    acc.push(
+        Diagnostic::hint(range, "Unnecessary braces in use statement".to_string()).with_fix(Some(
+            Fix::new(
+                "Remove unnecessary braces",
+                SourceFileEdit { file_id, edit }.into(),
+                range,
+            ),
+        )),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_check_useless_braces() {
+        check_no_diagnostics(
+            r#"
+use a;
+use a::{b, c};
+"#,
+        );
+
+        check_fix(
+            r#"
+mod b {}
+use {<|>b};
+"#,
+            r#"
+mod b {}
+use b;
+"#,
+        );
+
+        check_fix(
+            r#"
+mod a { pub mod b {} }
+use a::{<|>b};
+"#,
+            r#"
+mod a { pub mod b {} }
+use a::b;
+"#,
+        );
+
+        check_fix(
+            r#"
+mod a {}
+use a::{<|>self};
+"#,
+            r#"
+mod a {}
+use a;
+"#,
+        );
+    }
+}
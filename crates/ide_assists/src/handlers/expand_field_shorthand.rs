@@ -0,0 +1,167 @@
+//! Expands `Foo { field }` back to `Foo { field: field }`. The inverse of the
+//! struct field shorthand simplification offered by `field_shorthand`'s
+//! diagnostic, useful when the user wants to rename just the binding, or
+//! replace the shorthand with a different expression or pattern, without
+//! first retyping the field name by hand.
+
+use syntax::{ast, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: expand_field_shorthand
+//
+// Expands a struct field shorthand to fully specify it.
+//
+// ```
+// struct A { a: &'static str }
+// fn main() {
+//     let a = "haha";
+//     A { a<|> }
+// }
+// ```
+// ->
+// ```
+// struct A { a: &'static str }
+// fn main() {
+//     let a = "haha";
+//     A { a: a }
+// }
+// ```
+pub(crate) fn expand_field_shorthand(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    if let Some(field) = ctx.find_node_at_offset::<ast::RecordExprField>() {
+        return expand_expr_field_shorthand(acc, field);
+    }
+    if let Some(field) = ctx.find_node_at_offset::<ast::RecordPatField>() {
+        return expand_pat_field_shorthand(acc, field);
+    }
+    None
+}
+
+fn expand_expr_field_shorthand(acc: &mut Assists, field: ast::RecordExprField) -> Option<()> {
+    // A shorthand expr field (`A { a }`) has no `name_ref` child of its own; the identifier
+    // is both the field name and the expression, living as a bare path in `expr()`.
+    if field.name_ref().is_some() {
+        return None;
+    }
+    let name_ref = field.field_name()?;
+    if name_ref.as_tuple_field().is_some() {
+        return None;
+    }
+
+    let field_name = name_ref.syntax().text().to_string();
+    let expr_range = field.expr()?.syntax().text_range();
+    acc.add(
+        AssistId("expand_field_shorthand", AssistKind::RefactorRewrite),
+        "Expand field shorthand",
+        field.syntax().text_range(),
+        |builder| builder.insert(expr_range.start(), format!("{}: ", field_name)),
+    )
+}
+
+fn expand_pat_field_shorthand(acc: &mut Assists, field: ast::RecordPatField) -> Option<()> {
+    // A shorthand pattern field (`A { a }`) has no `name_ref` of its own; the field name
+    // is the `IdentPat` that doubles as the binding, unlike the fully-specified `a: a` form.
+    if field.name_ref().is_some() {
+        return None;
+    }
+    let ident_pat = match field.pat()? {
+        ast::Pat::IdentPat(it) => it,
+        _ => return None,
+    };
+    let field_name = ident_pat.name()?.text().to_string();
+
+    let pat_range = ident_pat.syntax().text_range();
+    acc.add(
+        AssistId("expand_field_shorthand", AssistKind::RefactorRewrite),
+        "Expand field shorthand",
+        field.syntax().text_range(),
+        |builder| builder.insert(pat_range.start(), format!("{}: ", field_name)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn expand_expr_field_shorthand() {
+        check_assist(
+            expand_field_shorthand,
+            r#"
+struct A { a: &'static str }
+fn main() {
+    let a = "haha";
+    A { a<|> }
+}
+"#,
+            r#"
+struct A { a: &'static str }
+fn main() {
+    let a = "haha";
+    A { a: a }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_expr_field_shorthand_not_applicable() {
+        check_assist_not_applicable(
+            expand_field_shorthand,
+            r#"
+struct A { a: &'static str }
+fn main() {
+    let a = "haha";
+    A { a<|>: "hello" }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_pat_field_shorthand() {
+        check_assist(
+            expand_field_shorthand,
+            r#"
+struct A { a: &'static str }
+fn f(a: A) {
+    let A { a<|> } = a;
+}
+"#,
+            r#"
+struct A { a: &'static str }
+fn f(a: A) {
+    let A { a: a } = a;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_pat_field_shorthand_not_applicable() {
+        check_assist_not_applicable(
+            expand_field_shorthand,
+            r#"
+struct A { a: &'static str }
+fn f(a: A) {
+    let A { a<|>: hello } = a;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn expand_tuple_field_shorthand_not_applicable() {
+        check_assist_not_applicable(
+            expand_field_shorthand,
+            r#"
+struct A(usize);
+fn main() {
+    A { 0<|>: 0 };
+}
+"#,
+        );
+    }
+}